@@ -0,0 +1,143 @@
+//! Typed builders for a small subset of Slack's Block Kit.
+//!
+//! [`Blocks`] accumulates [`Block`]s and renders them with [`Blocks::build`]
+//! into the `serde_json::Value` that `post_message`, `post_ephemeral`, and
+//! `open_modal` accept as `blocks`. Only sections, dividers, and button
+//! actions are modeled so far; anything else still needs a raw
+//! `serde_json::json!` value.
+
+use serde::Serialize;
+
+/// A Block Kit text object (`plain_text` or `mrkdwn`).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum Text {
+    #[serde(rename = "plain_text")]
+    PlainText {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    #[serde(rename = "mrkdwn")]
+    Mrkdwn { text: String },
+}
+
+impl Text {
+    /// Builds a `plain_text` text object.
+    pub fn plain(text: impl Into<String>) -> Self {
+        Text::PlainText {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    /// Builds a `mrkdwn` text object.
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Text::Mrkdwn { text: text.into() }
+    }
+}
+
+/// A clickable button element, usually placed inside a [`Block::Actions`] or
+/// a section's `accessory`. Serializes with `"type": "button"`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename = "button")]
+pub struct Button {
+    pub text: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+impl Button {
+    /// Creates a button with the given label and `action_id`.
+    pub fn new(text: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            text: Text::plain(text),
+            action_id: action_id.into(),
+            value: None,
+            style: None,
+        }
+    }
+
+    /// Sets the button's `value`, echoed back in the resulting interactivity payload.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the button's style (`"primary"` or `"danger"`).
+    pub fn style(mut self, style: impl Into<String>) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+}
+
+/// A single element of a Block Kit `blocks` array.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum Block {
+    #[serde(rename = "section")]
+    Section {
+        text: Text,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        accessory: Option<Button>,
+    },
+    #[serde(rename = "actions")]
+    Actions { elements: Vec<Button> },
+    #[serde(rename = "divider")]
+    Divider,
+}
+
+/// A builder that accumulates [`Block`]s and yields the `serde_json::Value`
+/// that `post_message`, `post_ephemeral`, and `open_modal` already accept.
+#[derive(Debug, Default)]
+pub struct Blocks {
+    blocks: Vec<Block>,
+}
+
+impl Blocks {
+    /// Starts an empty block list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a `section` block with the given text and no accessory.
+    pub fn section(mut self, text: Text) -> Self {
+        self.blocks.push(Block::Section {
+            text,
+            accessory: None,
+        });
+        self
+    }
+
+    /// Appends a `section` block with a button accessory.
+    pub fn section_with_accessory(mut self, text: Text, accessory: Button) -> Self {
+        self.blocks.push(Block::Section {
+            text,
+            accessory: Some(accessory),
+        });
+        self
+    }
+
+    /// Appends an `actions` block containing the given buttons.
+    pub fn actions(mut self, elements: impl IntoIterator<Item = Button>) -> Self {
+        self.blocks.push(Block::Actions {
+            elements: elements.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Appends a `divider` block.
+    pub fn divider(mut self) -> Self {
+        self.blocks.push(Block::Divider);
+        self
+    }
+
+    /// Converts the accumulated blocks into the `serde_json::Value` that
+    /// `post_message`/`post_ephemeral`/`open_modal` accept as `blocks`.
+    pub fn build(self) -> serde_json::Value {
+        serde_json::to_value(self.blocks).expect("Block serialization is infallible")
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Errors that can occur when talking to the Slack API.
+#[derive(Debug)]
+pub enum SlackClientError {
+    /// The HTTP request itself failed (DNS, TLS, timeout, etc.).
+    HttpError(reqwest::Error),
+    /// Slack accepted the request but responded with `"ok": false`.
+    ApiError {
+        error: String,
+        response: serde_json::Value,
+    },
+    /// Slack returned a 429; `retry_after` is the `Retry-After` header in seconds, if present.
+    RateLimited { retry_after: Option<u64> },
+    /// A Slack API response, or an inbound Slack request checked by
+    /// `events`, was malformed or failed verification.
+    ProtocolError(String),
+}
+
+impl fmt::Display for SlackClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlackClientError::HttpError(e) => write!(f, "HTTP request to Slack failed: {e}"),
+            SlackClientError::ApiError { error, .. } => {
+                write!(f, "Slack API returned an error: {error}")
+            }
+            SlackClientError::RateLimited { retry_after } => match retry_after {
+                Some(secs) => write!(f, "rate limited by Slack, retry after {secs}s"),
+                None => write!(f, "rate limited by Slack"),
+            },
+            SlackClientError::ProtocolError(msg) => write!(f, "unexpected Slack response: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SlackClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SlackClientError::HttpError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for SlackClientError {
+    fn from(e: reqwest::Error) -> Self {
+        SlackClientError::HttpError(e)
+    }
+}
@@ -1,13 +1,37 @@
 use reqwest::Client;
 use serde::Deserialize;
 
+pub mod blocks;
+mod error;
+pub mod events;
+pub use error::SlackClientError;
+
+/// A deserialized Slack API response that can report whether Slack accepted the call.
+///
+/// Implemented by every `...Response` struct so the client can share one place
+/// that turns `"ok": false` into a `SlackClientError::ApiError`. Public
+/// because `api_call` is public and bounds its generic response type on it.
+pub trait SlackApiResult {
+    fn ok(&self) -> bool;
+    fn error(&self) -> Option<&str>;
+}
+
+/// The default Slack API base URL.
+const DEFAULT_BASE_URL: &str = "https://slack.com/api";
+
+/// How many times `call_with_retry` will sleep-and-retry a rate-limited
+/// request before giving up and surfacing `SlackClientError::RateLimited`.
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
 /// Simple Slack client
 ///
 /// - `token`: your Slack bot token (e.g. "xoxb-xxxx-....")
 /// - `http_client`: a Reqwest Client for making API calls
+/// - `base_url`: the Slack API base URL, overridable for testing against a mock server
 pub struct SlackClient {
     token: String,
     http_client: Client,
+    base_url: String,
 }
 
 impl SlackClient {
@@ -16,6 +40,80 @@ impl SlackClient {
         Self {
             token: token.into(),
             http_client: Client::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    /// Overrides the Slack API base URL (defaults to `https://slack.com/api`).
+    ///
+    /// Primarily useful for pointing the client at a mock server in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Low-level helper that every JSON Slack API wrapper method is built on
+    /// (`upload_file` sends a multipart form and builds its own request).
+    ///
+    /// Posts `body` as JSON to `{base_url}/{method}`, authenticating with
+    /// `token` if given, or the client's own token otherwise. Future
+    /// endpoints only need to call this with the right method name and body.
+    #[tracing::instrument(
+        skip(self, method, body, token),
+        fields(
+            slack.method = %method,
+            slack.channel = body.get("channel").and_then(|c| c.as_str()).unwrap_or_default(),
+            http.status = tracing::field::Empty,
+            slack.error = tracing::field::Empty,
+        )
+    )]
+    pub async fn api_call<T>(
+        &self,
+        method: &str,
+        body: serde_json::Value,
+        token: Option<&str>,
+    ) -> Result<T, SlackClientError>
+    where
+        T: serde::de::DeserializeOwned + SlackApiResult,
+    {
+        let url = format!("{}/{}", self.base_url, method);
+
+        let resp = self
+            .http_client
+            .post(url)
+            .bearer_auth(token.unwrap_or(&self.token))
+            .json(&body)
+            .send()
+            .await?;
+
+        self.parse_response_instrumented(resp).await
+    }
+
+    /// Records the resolved HTTP status and, on failure, the Slack error
+    /// onto the current tracing span before delegating to `parse_response`.
+    ///
+    /// Shared by every method that calls out to Slack, so each gets the
+    /// same span fields regardless of whether it goes through `api_call` or
+    /// builds its own request (e.g. `upload_file`'s multipart form).
+    async fn parse_response_instrumented<T>(
+        &self,
+        resp: reqwest::Response,
+    ) -> Result<T, SlackClientError>
+    where
+        T: serde::de::DeserializeOwned + SlackApiResult,
+    {
+        let span = tracing::Span::current();
+        span.record("http.status", resp.status().as_u16() as u64);
+
+        match self.parse_response(resp).await {
+            Ok(parsed) => Ok(parsed),
+            Err(err) => {
+                if let SlackClientError::RateLimited { retry_after } = &err {
+                    tracing::warn!(retry_after, "Slack rate limited this request");
+                }
+                span.record("slack.error", tracing::field::display(&err));
+                Err(err)
+            }
         }
     }
 
@@ -34,9 +132,7 @@ impl SlackClient {
         channel: &str,
         text: &str,
         blocks: Option<serde_json::Value>,
-    ) -> Result<SlackPostMessageResponse, reqwest::Error> {
-        let url = "https://slack.com/api/chat.postMessage";
-
+    ) -> Result<SlackPostMessageResponse, SlackClientError> {
         // Construct the payload
         let mut body = serde_json::json!({
             "channel": channel,
@@ -48,17 +144,7 @@ impl SlackClient {
             body["blocks"] = blocks_json;
         }
 
-        let resp = self
-            .http_client
-            .post(url)
-            .bearer_auth(&self.token) // pass token as Bearer
-            .json(&body)
-            .send()
-            .await?;
-
-        // Deserialize Slack's JSON response
-        let slack_resp = resp.json::<SlackPostMessageResponse>().await?;
-        Ok(slack_resp)
+        self.api_call("chat.postMessage", body, None).await
     }
 
     // ----------------------------------------------------------------
@@ -78,9 +164,7 @@ impl SlackClient {
         user_id: &str,
         text: &str,
         blocks: Option<serde_json::Value>,
-    ) -> Result<SlackEphemeralResponse, reqwest::Error> {
-        let url = "https://slack.com/api/chat.postEphemeral";
-
+    ) -> Result<SlackEphemeralResponse, SlackClientError> {
         let mut body = serde_json::json!({
             "channel": channel,
             "user": user_id,
@@ -91,16 +175,7 @@ impl SlackClient {
             body["blocks"] = blocks_json;
         }
 
-        let resp = self
-            .http_client
-            .post(url)
-            .bearer_auth(&self.token)
-            .json(&body)
-            .send()
-            .await?;
-
-        let slack_resp = resp.json::<SlackEphemeralResponse>().await?;
-        Ok(slack_resp)
+        self.api_call("chat.postEphemeral", body, None).await
     }
 
     // ----------------------------------------------------------------
@@ -118,24 +193,257 @@ impl SlackClient {
         &self,
         trigger_id: &str,
         view: serde_json::Value,
-    ) -> Result<SlackViewOpenResponse, reqwest::Error> {
-        let url = "https://slack.com/api/views.open";
+    ) -> Result<SlackViewOpenResponse, SlackClientError> {
+        let body = serde_json::json!({
+            "trigger_id": trigger_id,
+            "view": view
+        });
 
+        self.api_call("views.open", body, None).await
+    }
+
+    /// Updates an existing modal view via `views.update`.
+    ///
+    /// - `target`: which view to update, and whether to guard against a
+    ///   concurrent update with a `hash`
+    /// - `view`: the new view definition
+    pub async fn update_modal(
+        &self,
+        target: SlackViewTarget<'_>,
+        view: serde_json::Value,
+    ) -> Result<SlackViewOpenResponse, SlackClientError> {
+        let mut body = serde_json::json!({ "view": view });
+        target.apply_to(&mut body);
+
+        self.api_call("views.update", body, None).await
+    }
+
+    /// Pushes a new modal onto the view stack via `views.push`, for
+    /// wizard-style multi-step modals.
+    ///
+    /// - `trigger_id`: Provided by Slack when a user invokes an action (e.g. button click)
+    /// - `view`: a JSON object describing the new modal's structure
+    pub async fn push_modal(
+        &self,
+        trigger_id: &str,
+        view: serde_json::Value,
+    ) -> Result<SlackViewOpenResponse, SlackClientError> {
         let body = serde_json::json!({
             "trigger_id": trigger_id,
             "view": view
         });
 
+        self.api_call("views.push", body, None).await
+    }
+
+    /// Publishes an App Home view for a user via `views.publish`.
+    ///
+    /// - `user_id`: whose Home tab to publish to
+    /// - `view`: a `type: "home"` view definition
+    pub async fn publish_home(
+        &self,
+        user_id: &str,
+        view: serde_json::Value,
+    ) -> Result<SlackViewOpenResponse, SlackClientError> {
+        let body = serde_json::json!({
+            "user_id": user_id,
+            "view": view
+        });
+
+        self.api_call("views.publish", body, None).await
+    }
+
+    // ----------------------------------------------------------------
+    //  4) Upload a file via multipart form data
+    // ----------------------------------------------------------------
+    /// Uploads a file to one or more channels via `files.upload`.
+    ///
+    /// - `channels`: channel IDs or names to share the file to
+    /// - `file_bytes`: the raw file contents
+    /// - `filename`: the name Slack should show for the file
+    /// - `title`: optional display title, distinct from `filename`
+    /// - `initial_comment`: optional message posted alongside the file
+    /// - `thread_ts`: optional parent message `ts` to attach the upload to a thread
+    ///
+    /// Unlike the JSON endpoints above, `files.upload` takes a multipart
+    /// form, so this builds its own request rather than going through
+    /// `api_call` — it records the same span fields by hand instead.
+    #[tracing::instrument(
+        skip(self, channels, file_bytes, filename, title, initial_comment, thread_ts),
+        fields(
+            slack.method = "files.upload",
+            slack.channel = channels.join(","),
+            http.status = tracing::field::Empty,
+            slack.error = tracing::field::Empty,
+        )
+    )]
+    pub async fn upload_file(
+        &self,
+        channels: &[&str],
+        file_bytes: Vec<u8>,
+        filename: &str,
+        title: Option<&str>,
+        initial_comment: Option<&str>,
+        thread_ts: Option<&str>,
+    ) -> Result<SlackFileUploadResponse, SlackClientError> {
+        let url = format!("{}/files.upload", self.base_url);
+
+        let file_part = reqwest::multipart::Part::bytes(file_bytes).file_name(filename.to_string());
+
+        let mut form = reqwest::multipart::Form::new()
+            .text("channels", channels.join(","))
+            .part("file", file_part)
+            .text("filename", filename.to_string());
+
+        if let Some(title) = title {
+            form = form.text("title", title.to_string());
+        }
+        if let Some(initial_comment) = initial_comment {
+            form = form.text("initial_comment", initial_comment.to_string());
+        }
+        if let Some(thread_ts) = thread_ts {
+            form = form.text("thread_ts", thread_ts.to_string());
+        }
+
         let resp = self
             .http_client
             .post(url)
             .bearer_auth(&self.token)
-            .json(&body)
+            .multipart(form)
             .send()
             .await?;
 
-        let slack_resp = resp.json::<SlackViewOpenResponse>().await?;
-        Ok(slack_resp)
+        self.parse_response_instrumented(resp).await
+    }
+
+    // ----------------------------------------------------------------
+    //  5) Discover channels and users
+    // ----------------------------------------------------------------
+    /// Lists channels the bot can see via `conversations.list`, transparently
+    /// following pagination until all channels have been collected.
+    ///
+    /// - `types`: comma-separated conversation types to include (e.g. `"public_channel,private_channel"`); `None` uses Slack's default
+    /// - `exclude_archived`: whether to omit archived conversations
+    pub async fn list_conversations(
+        &self,
+        types: Option<&str>,
+        exclude_archived: bool,
+    ) -> Result<Vec<SlackChannel>, SlackClientError> {
+        let mut channels = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut body = serde_json::json!({ "exclude_archived": exclude_archived });
+            if let Some(types) = types {
+                body["types"] = serde_json::Value::from(types);
+            }
+            if let Some(cursor) = &cursor {
+                body["cursor"] = serde_json::Value::from(cursor.as_str());
+            }
+
+            let resp: SlackConversationsListResponse = self
+                .call_with_retry("conversations.list", body)
+                .await?;
+            channels.extend(resp.channels);
+
+            match next_cursor(resp.response_metadata) {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(channels)
+    }
+
+    /// Lists workspace members via `users.list`, transparently following
+    /// pagination until all users have been collected.
+    pub async fn list_users(&self) -> Result<Vec<SlackUser>, SlackClientError> {
+        let mut users = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut body = serde_json::json!({});
+            if let Some(cursor) = &cursor {
+                body["cursor"] = serde_json::Value::from(cursor.as_str());
+            }
+
+            let resp: SlackUsersListResponse = self.call_with_retry("users.list", body).await?;
+            users.extend(resp.members);
+
+            match next_cursor(resp.response_metadata) {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(users)
+    }
+
+    /// Looks up a single user via `users.info`.
+    pub async fn user_info(&self, user_id: &str) -> Result<SlackUser, SlackClientError> {
+        let body = serde_json::json!({ "user": user_id });
+        let resp: SlackUserInfoResponse = self.call_with_retry("users.info", body).await?;
+        Ok(resp.user)
+    }
+
+    /// Calls `api_call`, transparently sleeping and retrying when Slack
+    /// responds with a rate limit instead of surfacing it to the caller.
+    ///
+    /// Gives up after `MAX_RATE_LIMIT_RETRIES` retries, surfacing the last
+    /// `RateLimited` error, so a sustained outage or misconfigured rate
+    /// limit can't sleep a caller forever.
+    async fn call_with_retry<T>(
+        &self,
+        method: &str,
+        body: serde_json::Value,
+    ) -> Result<T, SlackClientError>
+    where
+        T: serde::de::DeserializeOwned + SlackApiResult,
+    {
+        let mut retries = 0;
+        loop {
+            match self.api_call(method, body.clone(), None).await {
+                Err(SlackClientError::RateLimited { retry_after })
+                    if retries < MAX_RATE_LIMIT_RETRIES =>
+                {
+                    retries += 1;
+                    let wait_secs = retry_after.unwrap_or(1);
+                    tokio::time::sleep(std::time::Duration::from_secs(wait_secs)).await;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// Turns a raw HTTP response into a typed Slack response, surfacing
+    /// rate limits and `"ok": false` as a `SlackClientError` instead of
+    /// leaving callers to check `resp.ok` themselves.
+    async fn parse_response<T>(&self, resp: reqwest::Response) -> Result<T, SlackClientError>
+    where
+        T: serde::de::DeserializeOwned + SlackApiResult,
+    {
+        if resp.status().as_u16() == 429 {
+            let retry_after = resp
+                .headers()
+                .get("Retry-After")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            return Err(SlackClientError::RateLimited { retry_after });
+        }
+
+        let raw: serde_json::Value = resp.json().await?;
+        let parsed: T = serde_json::from_value(raw.clone())
+            .map_err(|e| SlackClientError::ProtocolError(e.to_string()))?;
+
+        if !parsed.ok() {
+            let error = parsed.error().unwrap_or("unknown_error").to_string();
+            return Err(SlackClientError::ApiError {
+                error,
+                response: raw,
+            });
+        }
+
+        Ok(parsed)
     }
 }
 
@@ -153,6 +461,16 @@ pub struct SlackPostMessageResponse {
     // If needed, you can add more fields here
 }
 
+impl SlackApiResult for SlackPostMessageResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
 /// Slack's top-level response object for `chat.postEphemeral`
 #[derive(Debug, Deserialize)]
 pub struct SlackEphemeralResponse {
@@ -162,6 +480,16 @@ pub struct SlackEphemeralResponse {
     // ...
 }
 
+impl SlackApiResult for SlackEphemeralResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
 /// Slack's top-level response for `views.open`
 #[derive(Debug, Deserialize)]
 pub struct SlackViewOpenResponse {
@@ -170,6 +498,16 @@ pub struct SlackViewOpenResponse {
     pub error: Option<String>,
 }
 
+impl SlackApiResult for SlackViewOpenResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
 /// Minimal struct to reflect a Slack View object
 #[derive(Debug, Deserialize)]
 pub struct SlackView {
@@ -177,6 +515,161 @@ pub struct SlackView {
     // Add other fields as needed
 }
 
+/// Identifies which modal `views.update` should update, per
+/// <https://api.slack.com/methods/views.update>.
+pub enum SlackViewTarget<'a> {
+    /// The `view_id` returned in a prior `SlackViewOpenResponse`.
+    ViewId {
+        view_id: &'a str,
+        /// Optional hash from the prior response, to reject the update if
+        /// the view has changed concurrently.
+        hash: Option<&'a str>,
+    },
+    /// A caller-assigned `external_id` supplied when the view was opened.
+    ExternalId {
+        external_id: &'a str,
+        hash: Option<&'a str>,
+    },
+}
+
+impl SlackViewTarget<'_> {
+    fn apply_to(&self, body: &mut serde_json::Value) {
+        match self {
+            SlackViewTarget::ViewId { view_id, hash } => {
+                body["view_id"] = serde_json::Value::from(*view_id);
+                if let Some(hash) = hash {
+                    body["hash"] = serde_json::Value::from(*hash);
+                }
+            }
+            SlackViewTarget::ExternalId { external_id, hash } => {
+                body["external_id"] = serde_json::Value::from(*external_id);
+                if let Some(hash) = hash {
+                    body["hash"] = serde_json::Value::from(*hash);
+                }
+            }
+        }
+    }
+}
+
+/// Slack's top-level response for `files.upload`
+#[derive(Debug, Deserialize)]
+pub struct SlackFileUploadResponse {
+    pub ok: bool,
+    pub file: Option<SlackFile>,
+    pub error: Option<String>,
+}
+
+impl SlackApiResult for SlackFileUploadResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// Minimal struct to reflect a Slack File object
+#[derive(Debug, Deserialize)]
+pub struct SlackFile {
+    pub id: String,
+    pub name: Option<String>,
+    pub url_private: Option<String>,
+    // Add other fields as needed
+}
+
+/// Cursor-pagination metadata shared by `conversations.list` and `users.list`.
+#[derive(Debug, Deserialize)]
+pub struct SlackResponseMetadata {
+    pub next_cursor: Option<String>,
+}
+
+/// Pulls the next page's cursor out of a response's `response_metadata`,
+/// treating Slack's empty-string "no more pages" marker as `None`.
+fn next_cursor(metadata: Option<SlackResponseMetadata>) -> Option<String> {
+    metadata
+        .and_then(|m| m.next_cursor)
+        .filter(|cursor| !cursor.is_empty())
+}
+
+/// Slack's top-level response for `conversations.list`
+#[derive(Debug, Deserialize)]
+pub struct SlackConversationsListResponse {
+    pub ok: bool,
+    #[serde(default)]
+    pub channels: Vec<SlackChannel>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+    pub error: Option<String>,
+}
+
+impl SlackApiResult for SlackConversationsListResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// Minimal struct to reflect a Slack conversation (channel) object
+#[derive(Debug, Deserialize)]
+pub struct SlackChannel {
+    pub id: String,
+    pub name: Option<String>,
+    pub is_archived: Option<bool>,
+    // Add other fields as needed
+}
+
+/// Slack's top-level response for `users.list`
+#[derive(Debug, Deserialize)]
+pub struct SlackUsersListResponse {
+    pub ok: bool,
+    #[serde(default)]
+    pub members: Vec<SlackUser>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+    pub error: Option<String>,
+}
+
+impl SlackApiResult for SlackUsersListResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// Slack's top-level response for `users.info`
+#[derive(Debug, Deserialize)]
+pub struct SlackUserInfoResponse {
+    pub ok: bool,
+    #[serde(default)]
+    pub user: SlackUser,
+    pub error: Option<String>,
+}
+
+impl SlackApiResult for SlackUserInfoResponse {
+    fn ok(&self) -> bool {
+        self.ok
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// Minimal struct to reflect a Slack user object
+#[derive(Debug, Default, Deserialize)]
+pub struct SlackUser {
+    pub id: String,
+    pub name: Option<String>,
+    pub real_name: Option<String>,
+    pub tz: Option<String>,
+    // Add other fields as needed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,4 +853,280 @@ mod tests {
     //         }
     //     }
     // }
+
+    // ------------------------------------------------------------------
+    //  Mock-server tests: exercise `with_base_url` against a hand-rolled
+    //  local HTTP server instead of hitting real Slack, so these run
+    //  unconditionally (no SLACK_BOT_TOKEN needed).
+    // ------------------------------------------------------------------
+
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Reads a full HTTP request off `socket`, following its `Content-Length`
+    /// header so multipart bodies spanning several TCP reads aren't
+    /// truncated, and returns just the body.
+    async fn read_request_body(socket: &mut TcpStream) -> String {
+        let mut received = Vec::new();
+        let mut chunk = [0u8; 8 * 1024];
+
+        loop {
+            let n = socket.read(&mut chunk).await.unwrap_or(0);
+            if n == 0 {
+                break;
+            }
+            received.extend_from_slice(&chunk[..n]);
+
+            let Some(headers_end) = received.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&received[..headers_end]);
+            let content_length: usize = headers
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().to_string())
+                })
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0);
+
+            if received.len() >= headers_end + 4 + content_length {
+                break;
+            }
+        }
+
+        let request = String::from_utf8_lossy(&received).into_owned();
+        request
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body)
+            .unwrap_or("")
+            .to_string()
+    }
+
+    /// A single canned HTTP response for the mock server to hand back.
+    struct MockResponse {
+        status: u16,
+        headers: Vec<(&'static str, String)>,
+        body: String,
+    }
+
+    impl MockResponse {
+        fn ok(body: impl Into<String>) -> Self {
+            Self {
+                status: 200,
+                headers: vec![],
+                body: body.into(),
+            }
+        }
+
+        fn rate_limited(retry_after_secs: u64) -> Self {
+            Self {
+                status: 429,
+                headers: vec![("Retry-After", retry_after_secs.to_string())],
+                body: String::new(),
+            }
+        }
+    }
+
+    /// Spins up a local TCP server that hands back `responses` in order, one
+    /// per accepted connection, and returns its base URL along with the
+    /// request bodies it received (for assertions on what the client sent).
+    async fn spawn_mock_server(responses: Vec<MockResponse>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let captured_for_task = captured.clone();
+
+        tokio::spawn(async move {
+            for response in responses {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+
+                let body = read_request_body(&mut socket).await;
+                captured_for_task.lock().unwrap().push(body);
+
+                let status_line = match response.status {
+                    200 => "200 OK",
+                    429 => "429 Too Many Requests",
+                    other => panic!("mock server doesn't know status {other}"),
+                };
+                let mut extra_headers = String::new();
+                for (name, value) in &response.headers {
+                    extra_headers.push_str(&format!("{name}: {value}\r\n"));
+                }
+
+                let payload = format!(
+                    "HTTP/1.1 {status_line}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{extra_headers}Connection: close\r\n\r\n{}",
+                    response.body.len(),
+                    response.body,
+                );
+                let _ = socket.write_all(payload.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (format!("http://{addr}"), captured)
+    }
+
+    #[tokio::test]
+    async fn with_base_url_routes_api_call_to_a_mock_server() {
+        let (base_url, _captured) = spawn_mock_server(vec![MockResponse::ok(
+            r#"{"ok":true,"channel":"C1","ts":"123.456"}"#,
+        )])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let resp = client.post_message("C1", "hi", None).await.unwrap();
+
+        assert!(resp.ok);
+        assert_eq!(resp.channel.as_deref(), Some("C1"));
+    }
+
+    #[tokio::test]
+    async fn post_message_turns_ok_false_into_an_api_error() {
+        let (base_url, _captured) = spawn_mock_server(vec![MockResponse::ok(
+            r#"{"ok":false,"error":"channel_not_found"}"#,
+        )])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let result = client.post_message("C1", "hi", None).await;
+
+        match result {
+            Err(SlackClientError::ApiError { error, .. }) => {
+                assert_eq!(error, "channel_not_found");
+            }
+            other => panic!("expected ApiError, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn post_message_surfaces_a_rate_limit_without_retrying() {
+        let (base_url, _captured) = spawn_mock_server(vec![MockResponse::rate_limited(7)]).await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let result = client.post_message("C1", "hi", None).await;
+
+        match result {
+            Err(SlackClientError::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(7));
+            }
+            other => panic!("expected RateLimited, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_file_sends_a_multipart_form() {
+        let (base_url, captured) = spawn_mock_server(vec![MockResponse::ok(
+            r#"{"ok":true,"file":{"id":"F1","name":"log.txt","url_private":"https://files.slack.com/F1"}}"#,
+        )])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let resp = client
+            .upload_file(&["C1"], b"hello world".to_vec(), "log.txt", None, None, None)
+            .await
+            .unwrap();
+
+        assert!(resp.ok);
+        assert_eq!(resp.file.unwrap().id, "F1");
+
+        let bodies = captured.lock().unwrap();
+        assert!(bodies[0].contains("hello world"));
+        assert!(bodies[0].contains("filename=\"log.txt\""));
+    }
+
+    #[tokio::test]
+    async fn update_modal_sends_view_id_and_hash() {
+        let (base_url, captured) = spawn_mock_server(vec![MockResponse::ok(
+            r#"{"ok":true,"view":{"id":"V1"}}"#,
+        )])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let resp = client
+            .update_modal(
+                SlackViewTarget::ViewId {
+                    view_id: "V1",
+                    hash: Some("abc123"),
+                },
+                serde_json::json!({ "type": "modal" }),
+            )
+            .await
+            .unwrap();
+
+        assert!(resp.ok);
+        let bodies = captured.lock().unwrap();
+        assert!(bodies[0].contains("\"view_id\":\"V1\""));
+        assert!(bodies[0].contains("\"hash\":\"abc123\""));
+    }
+
+    #[tokio::test]
+    async fn push_modal_and_publish_home_round_trip() {
+        let (base_url, _captured) = spawn_mock_server(vec![
+            MockResponse::ok(r#"{"ok":true,"view":{"id":"V2"}}"#),
+            MockResponse::ok(r#"{"ok":true,"view":{"id":"V3"}}"#),
+        ])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+
+        let pushed = client
+            .push_modal("T1", serde_json::json!({ "type": "modal" }))
+            .await
+            .unwrap();
+        assert_eq!(pushed.view.unwrap().id, "V2");
+
+        let published = client
+            .publish_home("U1", serde_json::json!({ "type": "home" }))
+            .await
+            .unwrap();
+        assert_eq!(published.view.unwrap().id, "V3");
+    }
+
+    #[test]
+    fn blocks_builder_produces_the_expected_json() {
+        use crate::blocks::{Blocks, Button, Text};
+
+        let value = Blocks::new()
+            .section(Text::mrkdwn("hello"))
+            .divider()
+            .actions([Button::new("Yes", "yes_action").value("yes")])
+            .build();
+
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {"type": "section", "text": {"type": "mrkdwn", "text": "hello"}},
+                {"type": "divider"},
+                {"type": "actions", "elements": [
+                    {"type": "button", "text": {"type": "plain_text", "text": "Yes"}, "action_id": "yes_action", "value": "yes"}
+                ]},
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn list_conversations_follows_cursor_and_retries_after_rate_limit() {
+        let (base_url, _captured) = spawn_mock_server(vec![
+            MockResponse::rate_limited(0),
+            MockResponse::ok(
+                r#"{"ok":true,"channels":[{"id":"C1","name":"general"}],"response_metadata":{"next_cursor":"page2"}}"#,
+            ),
+            MockResponse::ok(
+                r#"{"ok":true,"channels":[{"id":"C2","name":"random"}],"response_metadata":{"next_cursor":""}}"#,
+            ),
+        ])
+        .await;
+
+        let client = SlackClient::new("test-token").with_base_url(base_url);
+        let channels = client.list_conversations(None, true).await.unwrap();
+
+        assert_eq!(channels.len(), 2);
+        assert_eq!(channels[0].id, "C1");
+        assert_eq!(channels[1].id, "C2");
+    }
 }
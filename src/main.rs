@@ -31,6 +31,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 4) Post a channel message with that block
     let channel_id = "#general"; // or "C1234567" if you know the ID
+    // `?` already turns a Slack `"ok": false` response into an `Err`, so by
+    // the time we get here the post succeeded.
     let post_resp = slack_client
         .post_message(
             channel_id,
@@ -38,27 +40,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Some(blocks_with_button),
         )
         .await?;
-
-    if post_resp.ok {
-        println!(
-            "Successfully posted message to channel: {:?}",
-            post_resp.channel
-        );
-    } else {
-        eprintln!("Failed to post message: {:?}", post_resp.error);
-    }
+    println!(
+        "Successfully posted message to channel: {:?}",
+        post_resp.channel
+    );
 
     // 5) (Optional) Post an ephemeral message to a user in that channel
     //    Suppose you know the user’s Slack user ID: "U123456"
     let ephemeral_resp = slack_client
         .post_ephemeral(channel_id, "U123456", "Hello ephemeral", None)
         .await?;
-
-    if ephemeral_resp.ok {
-        println!("Ephemeral posted: {:?}", ephemeral_resp.message_ts);
-    } else {
-        eprintln!("Failed to post ephemeral: {:?}", ephemeral_resp.error);
-    }
+    println!("Ephemeral posted: {:?}", ephemeral_resp.message_ts);
 
     // 6) (Optional) Open a modal view
     //
@@ -97,12 +89,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let modal_resp = slack_client
         .open_modal(fake_trigger_id, my_modal_view)
         .await?;
-
-    if modal_resp.ok {
-        println!("Modal opened: {:?}", modal_resp.view);
-    } else {
-        eprintln!("Failed to open modal: {:?}", modal_resp.error);
-    }
+    println!("Modal opened: {:?}", modal_resp.view);
 
     Ok(())
 }